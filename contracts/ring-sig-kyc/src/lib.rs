@@ -1,9 +1,9 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Bytes, BytesN, Env, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, Symbol, Vec,
 };
-use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
+use soroban_sdk::crypto::bls12_381::{Fr, G1Affine, G2Affine};
 
 const G1_GENERATOR: [u8; 96] = [
     0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c, 0x4f, 0xa9, 0xac, 0x0f,
@@ -14,11 +14,29 @@ const G1_GENERATOR: [u8; 96] = [
     0x4e, 0x6f, 0x38, 0xba, 0x0e, 0xcb, 0x75, 0x1b, 0xad, 0x54, 0xdc, 0xd6, 0xb9, 0x39, 0xc2, 0xca,
 ];
 
+const G2_GENERATOR: [u8; 192] = [
+    0x02, 0x4a, 0xa2, 0xb2, 0xf0, 0x8f, 0x0a, 0x91, 0x26, 0x08, 0x05, 0x27, 0x2d, 0xc5, 0x10, 0x51,
+    0xc6, 0xe4, 0x7a, 0xd4, 0xfa, 0x40, 0x3b, 0x02, 0xb4, 0x51, 0x0b, 0x64, 0x7a, 0xe3, 0xd1, 0x77,
+    0x0b, 0xac, 0x03, 0x26, 0xa8, 0x05, 0xbb, 0xef, 0xd4, 0x80, 0x56, 0xc8, 0xc1, 0x21, 0xbd, 0xb8,
+    0x13, 0xe0, 0x2b, 0x60, 0x52, 0x71, 0x9f, 0x60, 0x7d, 0xac, 0xd3, 0xa0, 0x88, 0x27, 0x4f, 0x65,
+    0x59, 0x6b, 0xd0, 0xd0, 0x99, 0x20, 0xb6, 0x1a, 0xb5, 0xda, 0x61, 0xbb, 0xdc, 0x7f, 0x50, 0x49,
+    0x33, 0x4c, 0xf1, 0x12, 0x13, 0x94, 0x5d, 0x57, 0xe5, 0xac, 0x7d, 0x05, 0x5d, 0x04, 0x2b, 0x7e,
+    0x0c, 0xe5, 0xd5, 0x27, 0x72, 0x7d, 0x6e, 0x11, 0x8c, 0xc9, 0xcd, 0xc6, 0xda, 0x2e, 0x35, 0x1a,
+    0xad, 0xfd, 0x9b, 0xaa, 0x8c, 0xbd, 0xd3, 0xa7, 0x6d, 0x42, 0x9a, 0x69, 0x51, 0x60, 0xd1, 0x2c,
+    0x92, 0x3a, 0xc9, 0xcc, 0x3b, 0xac, 0xa2, 0x89, 0xe1, 0x93, 0x54, 0x86, 0x08, 0xb8, 0x28, 0x01,
+    0x06, 0x06, 0xc4, 0xa0, 0x2e, 0xa7, 0x34, 0xcc, 0x32, 0xac, 0xd2, 0xb0, 0x2b, 0xc2, 0x8b, 0x99,
+    0xcb, 0x3e, 0x28, 0x7e, 0x85, 0xa7, 0x63, 0xaf, 0x26, 0x74, 0x92, 0xab, 0x57, 0x2e, 0x99, 0xab,
+    0x3f, 0x37, 0x0d, 0x27, 0x5c, 0xec, 0x1d, 0xa1, 0xaa, 0xa9, 0x07, 0x5f, 0xf0, 0x5f, 0x79, 0xbe,
+];
+
 #[derive(Clone)]
 #[contracttype]
 pub struct RingSignature {
     pub challenge: BytesN<32>,
     pub responses: Vec<BytesN<32>>,
+    /// Linkability tag `I = sk * Hp(pk)`. Two signatures over the same
+    /// attribute ring that share an `image` were produced by the same key.
+    pub image: BytesN<96>,
 }
 
 #[contracttype]
@@ -27,6 +45,90 @@ pub struct KeyRingResult {
     pub ring: Vec<BytesN<96>>,
 }
 
+/// Group public key and participant identifiers produced by a FROST DKG.
+/// `create_ring_for_attribute` requires a threshold signature under
+/// `group_pk` from a quorum of `participant_ids` before it will store a
+/// new attribute ring.
+#[contracttype]
+pub struct IssuerGroup {
+    pub group_pk: BytesN<96>,
+    pub participant_ids: Vec<u32>,
+}
+
+/// Aggregated FROST threshold-Schnorr signature `(R, z)` over a message,
+/// satisfying `z*G == R + H(R || Y || m)*Y` for the group key `Y`.
+#[derive(Clone)]
+#[contracttype]
+pub struct FrostSignature {
+    pub r: BytesN<96>,
+    pub z: BytesN<32>,
+}
+
+/// ElGamal ciphertext `(R = k*G, C = k*PK + m*G)` over BLS12-381 G1.
+#[derive(Clone)]
+#[contracttype]
+pub struct ElGamalCiphertext {
+    pub r: BytesN<96>,
+    pub c: BytesN<96>,
+}
+
+/// One branch of a disjunctive Chaum-Pedersen ring proof: commitments
+/// `(a, b)` and the challenge/response pair `(e, s)` for a single candidate
+/// plaintext. Exactly one branch was honestly computed by the prover; the
+/// others were back-computed from sampled `(e, s)`, which is what makes the
+/// proof hide which branch is real.
+#[derive(Clone)]
+#[contracttype]
+pub struct RingProofBranch {
+    pub a: BytesN<96>,
+    pub b: BytesN<96>,
+    pub e: BytesN<32>,
+    pub s: BytesN<32>,
+}
+
+/// An ElGamal-encrypted attribute value together with a proof that the
+/// plaintext is one of `allowed_values`, without revealing which.
+#[derive(Clone)]
+#[contracttype]
+pub struct EncryptedAttribute {
+    pub pk: BytesN<96>,
+    pub allowed_values: Vec<BytesN<32>>,
+    pub ciphertext: ElGamalCiphertext,
+    pub branches: Vec<RingProofBranch>,
+}
+
+/// CL issuer public key `(X = x*G2, Y = y*G2)` for a credential schema.
+#[derive(Clone)]
+#[contracttype]
+pub struct ClIssuerKey {
+    pub x_g2: BytesN<192>,
+    pub y_g2: BytesN<192>,
+}
+
+/// A CL signature `(a, b, c)` over a holder's bundled attribute message.
+#[derive(Clone)]
+#[contracttype]
+pub struct ClCredential {
+    pub a: BytesN<96>,
+    pub b: BytesN<96>,
+    pub c: BytesN<96>,
+}
+
+/// A selective-disclosure presentation of a `ClCredential`. `credential`
+/// must be the output of `randomize_credential`, not a raw issued
+/// credential, or separate presentations become linkable via shared
+/// points. `hidden_commitment = m_H * b` is a commitment to the combined
+/// value of the undisclosed attributes, with a Schnorr proof of knowledge
+/// of `m_H` relating `b` to `hidden_commitment`.
+#[derive(Clone)]
+#[contracttype]
+pub struct ClPresentationProof {
+    pub credential: ClCredential,
+    pub hidden_commitment: BytesN<96>,
+    pub dleq_t: BytesN<96>,
+    pub dleq_z: BytesN<32>,
+}
+
 #[contracttype]
 pub enum DataKey {
     Ring,
@@ -34,6 +136,20 @@ pub enum DataKey {
     RingByAttribute(Symbol),
     Issuers,
     Admin,
+    /// Whether a given key image has already been spent against a given
+    /// attribute ring, used to enforce one-login-per-identity for linkable
+    /// ring signatures. Keyed per-image rather than as a growing `Vec` so a
+    /// replay check is a single storage lookup, not a linear scan over
+    /// every image ever spent.
+    KeyImages(Symbol, BytesN<96>),
+    /// FROST issuer quorum: group public key and participant identifiers.
+    IssuerGroup,
+    /// Encrypted attribute value and ring-membership proof, keyed by
+    /// attribute and submitter so concurrent submitters for the same
+    /// attribute don't clobber each other's ciphertext and proof.
+    EncryptedAttribute(Symbol, Address),
+    /// CL issuer public key for a given credential schema.
+    CredentialIssuer(Symbol),
 }
 
 #[contract]
@@ -86,17 +202,83 @@ impl RingSigContract {
             .unwrap_or(Vec::new(&env))
     }
 
-    /// Create or update a ring for a specific attribute
+    /// Configure the FROST issuer quorum (admin only). All future calls to
+    /// `create_ring_for_attribute` must carry a threshold signature under
+    /// `group_pk` from a subset of `participant_ids`.
+    pub fn set_issuer_group(env: Env, group_pk: BytesN<96>, participant_ids: Vec<u32>) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        let group = IssuerGroup { group_pk, participant_ids };
+        env.storage().instance().set(&DataKey::IssuerGroup, &group);
+    }
+
+    /// Get the configured FROST issuer group, if any.
+    pub fn get_issuer_group(env: Env) -> Option<IssuerGroup> {
+        env.storage().instance().get(&DataKey::IssuerGroup)
+    }
+
+    /// Create or update a ring for a specific attribute. Requires an
+    /// aggregated FROST threshold signature over `attribute || users ||
+    /// signer_ids` from the configured issuer quorum, rather than trusting
+    /// a single authenticated address. `signer_ids` names which quorum
+    /// members actually contributed to `frost_sig` and must each be a
+    /// registered `IssuerGroup::participant_ids` member; binding it into
+    /// the signed transcript is what makes `participant_ids` load-bearing
+    /// instead of stored-but-unchecked data.
     pub fn create_ring_for_attribute(
         env: Env,
-        issuer: Address,
         attribute: Symbol,
-        users: Vec<BytesN<96>>
+        users: Vec<BytesN<96>>,
+        signer_ids: Vec<u32>,
+        frost_sig: FrostSignature,
     ) {
-        issuer.require_auth();
+        let group: IssuerGroup = env.storage().instance().get(&DataKey::IssuerGroup)
+            .expect("Issuer group not configured");
 
-        // Verify issuer is registered (optional - could be enforced)
-        // For now, any authenticated address can create rings
+        if signer_ids.is_empty() {
+            panic!("No signers named");
+        }
+        for signer_id in signer_ids.iter() {
+            let mut is_member = false;
+            for participant_id in group.participant_ids.iter() {
+                if participant_id == signer_id {
+                    is_member = true;
+                    break;
+                }
+            }
+            if !is_member {
+                panic!("Signer is not a registered issuer quorum participant");
+            }
+        }
+
+        let bls = env.crypto().bls12_381();
+        let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
+        let group_pk = group.group_pk.clone();
+        let y = G1Affine::from_bytes(group_pk.clone());
+        let r = G1Affine::from_bytes(frost_sig.r.clone());
+        let z = Fr::from_bytes(frost_sig.z);
+
+        let mut msg = Bytes::from_slice(&env, &attribute.to_val().get_payload().to_be_bytes());
+        for u in users.iter() {
+            msg.append(&u.into());
+        }
+        for signer_id in signer_ids.iter() {
+            msg.append(&Bytes::from_slice(&env, &signer_id.to_be_bytes()));
+        }
+
+        let mut pre = Bytes::new(&env);
+        pre.append(&frost_sig.r.into());
+        pre.append(&group_pk.into());
+        pre.append(&msg);
+        let c = Fr::from_bytes(env.crypto().sha256(&pre).into());
+
+        let lhs = bls.g1_mul(&gen_g, &z);
+        let rhs = bls.g1_add(&r, &bls.g1_mul(&y, &c));
+        if lhs.to_bytes() != rhs.to_bytes() {
+            panic!("Invalid FROST threshold signature");
+        }
 
         env.storage().persistent().set(&DataKey::RingByAttribute(attribute), &users);
     }
@@ -121,6 +303,375 @@ impl RingSigContract {
         env.storage().persistent().get(&DataKey::LoginCount).unwrap_or(0u64)
     }
 
+    /// Map a compressed G1 public key to a second, independent base point
+    /// `Hp = hash_to_g1(pk)`, used for LSAG key images. This uses the host's
+    /// native hash-to-curve function (not `G(scalar)` for a hash-derived
+    /// scalar): nobody — including the signer — knows `Hp`'s discrete log
+    /// relative to `G`, so `image = sk * Hp` cannot be computed from `pk`
+    /// alone the way `H(pk) * pk` could. That unknown-discrete-log property
+    /// is what keeps the image from deanonymizing the signer.
+    fn hash_to_g1(env: &Env, pk: &BytesN<96>) -> G1Affine {
+        let bls = env.crypto().bls12_381();
+        let dst = Bytes::from_slice(env, b"RINGSIG-LSAG-H2C-");
+        bls.hash_to_g1(&pk.clone().into(), &dst)
+    }
+
+    /// Derive a synthetic nonce scalar for a ring-proof branch, RedDSA-style:
+    /// domain-separated and bound to the attribute and branch index, but
+    /// also to `secret_material` (the submitter's encryption randomness `k`,
+    /// the true plaintext `m`, and fresh caller-supplied entropy). Without
+    /// that secret material, decoy branch values would be fully determined
+    /// by public inputs `(domain, attribute, idx)` alone, letting anyone
+    /// precompute every decoy and single out the real branch by elimination
+    /// — defeating the proof's hiding property even without any reuse.
+    fn derive_branch_scalar(
+        env: &Env,
+        domain: &[u8],
+        attribute: &Symbol,
+        idx: u32,
+        secret_material: &Bytes,
+    ) -> Fr {
+        let mut pre = Bytes::from_slice(env, domain);
+        pre.append(&Bytes::from_slice(env, &attribute.to_val().get_payload().to_be_bytes()));
+        pre.append(&Bytes::from_slice(env, &idx.to_be_bytes()));
+        pre.append(secret_material);
+        Fr::from_bytes(env.crypto().sha256(&pre).into())
+    }
+
+    /// Encrypt an attribute value under `pk` and attach a disjunctive
+    /// Chaum-Pedersen proof that the plaintext is `allowed_values[true_idx]`
+    /// without revealing `true_idx`. Stores the ciphertext and proof under
+    /// `(attribute, submitter)`, so different submitters can each have a
+    /// live submission for the same attribute; call
+    /// `verify_encrypted_attribute` to check one.
+    ///
+    /// `extra` is fresh caller-supplied entropy (e.g. a counter or random
+    /// bytes) mixed into the decoy and nonce derivation alongside the
+    /// secret `k` and `m`, so branch values can't be precomputed from the
+    /// public `(attribute, idx)` pair alone.
+    pub fn submit_encrypted_attribute(
+        env: Env,
+        submitter: Address,
+        attribute: Symbol,
+        pk: BytesN<96>,
+        allowed_values: Vec<BytesN<32>>,
+        true_idx: u32,
+        m: BytesN<32>,
+        k: BytesN<32>,
+        extra: Bytes,
+    ) {
+        submitter.require_auth();
+        let bls = env.crypto().bls12_381();
+        let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
+        let pk_point = G1Affine::from_bytes(pk.clone());
+        let k_scalar = Fr::from_bytes(k.clone());
+        let m_scalar = Fr::from_bytes(m.clone());
+
+        let mut secret_material = Bytes::new(&env);
+        secret_material.append(&k.into());
+        secret_material.append(&m.into());
+        secret_material.append(&extra);
+
+        let r_point = bls.g1_mul(&gen_g, &k_scalar);
+        let c_point = bls.g1_add(&bls.g1_mul(&pk_point, &k_scalar), &bls.g1_mul(&gen_g, &m_scalar));
+
+        let n = allowed_values.len();
+        let j = true_idx;
+
+        // Placeholder commitments get overwritten for every index below;
+        // branch `j`'s commitments come from the nonce `t`, and its
+        // challenge and response are filled in once the total challenge is known.
+        let mut a_pts: Vec<BytesN<96>> = Vec::new(&env);
+        let mut b_pts: Vec<BytesN<96>> = Vec::new(&env);
+        let mut e_bytes: Vec<BytesN<32>> = Vec::new(&env);
+        let mut s_bytes: Vec<BytesN<32>> = Vec::new(&env);
+        for _ in 0..n {
+            a_pts.push_back(BytesN::from_array(&env, &G1_GENERATOR));
+            b_pts.push_back(BytesN::from_array(&env, &G1_GENERATOR));
+            e_bytes.push_back(BytesN::from_array(&env, &[0u8; 32]));
+            s_bytes.push_back(BytesN::from_array(&env, &[0u8; 32]));
+        }
+
+        let mut e_sum = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32]));
+        for i in 0..n {
+            if i == j {
+                continue;
+            }
+            let s_i = Self::derive_branch_scalar(&env, b"CP-RING-S", &attribute, i, &secret_material);
+            let e_i = Self::derive_branch_scalar(&env, b"CP-RING-E", &attribute, i, &secret_material);
+            let e_i_bytes = e_i.to_bytes();
+            let m_i = Fr::from_bytes(allowed_values.get_unchecked(i));
+            let neg_e_i = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])) - Fr::from_bytes(e_i_bytes.clone());
+            let neg_mi = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])) - m_i;
+            let c_minus_mi_g = bls.g1_add(&c_point, &bls.g1_mul(&gen_g, &neg_mi));
+
+            let a_i = bls.g1_add(&bls.g1_mul(&gen_g, &s_i), &bls.g1_mul(&r_point, &neg_e_i));
+            let b_i = bls.g1_add(&bls.g1_mul(&pk_point, &s_i), &bls.g1_mul(&c_minus_mi_g, &neg_e_i));
+
+            a_pts.set(i, a_i.to_bytes());
+            b_pts.set(i, b_i.to_bytes());
+            e_bytes.set(i, e_i_bytes);
+            s_bytes.set(i, s_i.to_bytes());
+            e_sum = e_sum + e_i;
+        }
+
+        let t = Self::derive_branch_scalar(&env, b"CP-RING-NONCE", &attribute, j, &secret_material);
+        a_pts.set(j, bls.g1_mul(&gen_g, &t).to_bytes());
+        b_pts.set(j, bls.g1_mul(&pk_point, &t).to_bytes());
+
+        let mut base = Bytes::new(&env);
+        base.append(&r_point.to_bytes().into());
+        base.append(&c_point.to_bytes().into());
+        for a in a_pts.iter() {
+            base.append(&a.into());
+        }
+        for b in b_pts.iter() {
+            base.append(&b.into());
+        }
+        let e_total = Fr::from_bytes(env.crypto().sha256(&base).into());
+        let e_j = e_total - e_sum;
+        let e_j_bytes = e_j.to_bytes();
+        let s_j = t + e_j * k_scalar;
+        e_bytes.set(j, e_j_bytes);
+        s_bytes.set(j, s_j.to_bytes());
+
+        let mut branches: Vec<RingProofBranch> = Vec::new(&env);
+        for i in 0..n {
+            branches.push_back(RingProofBranch {
+                a: a_pts.get_unchecked(i),
+                b: b_pts.get_unchecked(i),
+                e: e_bytes.get_unchecked(i),
+                s: s_bytes.get_unchecked(i),
+            });
+        }
+
+        let record = EncryptedAttribute {
+            pk,
+            allowed_values,
+            ciphertext: ElGamalCiphertext { r: r_point.to_bytes(), c: c_point.to_bytes() },
+            branches,
+        };
+        env.storage().persistent().set(&DataKey::EncryptedAttribute(attribute, submitter), &record);
+    }
+
+    /// Verify a submitter's stored encrypted attribute ring-membership
+    /// proof: recompute the total challenge `E` from the proof's own
+    /// commitments and check it splits across branches that each satisfy
+    /// the `G`/`PK` equations for their candidate plaintext.
+    pub fn verify_encrypted_attribute(env: Env, submitter: Address, attribute: Symbol) -> bool {
+        let record: EncryptedAttribute = match env.storage().persistent().get(&DataKey::EncryptedAttribute(attribute, submitter)) {
+            Some(r) => r,
+            None => return false,
+        };
+        let n = record.branches.len();
+        if n == 0 || n != record.allowed_values.len() {
+            return false;
+        }
+
+        let bls = env.crypto().bls12_381();
+        let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
+        let pk_point = G1Affine::from_bytes(record.pk.clone());
+        let r_point = G1Affine::from_bytes(record.ciphertext.r.clone());
+        let c_point = G1Affine::from_bytes(record.ciphertext.c.clone());
+
+        let mut base = Bytes::new(&env);
+        base.append(&record.ciphertext.r.clone().into());
+        base.append(&record.ciphertext.c.clone().into());
+        for branch in record.branches.iter() {
+            base.append(&branch.a.clone().into());
+        }
+        for branch in record.branches.iter() {
+            base.append(&branch.b.clone().into());
+        }
+        let e_total = Fr::from_bytes(env.crypto().sha256(&base).into());
+
+        let mut e_sum = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32]));
+        for i in 0..n {
+            let branch = record.branches.get_unchecked(i);
+            let e_i = Fr::from_bytes(branch.e.clone());
+            let s_i = Fr::from_bytes(branch.s.clone());
+            let a_i = G1Affine::from_bytes(branch.a.clone());
+            let b_i = G1Affine::from_bytes(branch.b.clone());
+            let m_i = Fr::from_bytes(record.allowed_values.get_unchecked(i));
+
+            let lhs_a = bls.g1_mul(&gen_g, &s_i);
+            let rhs_a = bls.g1_add(&a_i, &bls.g1_mul(&r_point, &e_i));
+            if lhs_a.to_bytes() != rhs_a.to_bytes() {
+                return false;
+            }
+
+            let neg_mi = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])) - m_i;
+            let c_minus_mi_g = bls.g1_add(&c_point, &bls.g1_mul(&gen_g, &neg_mi));
+            let lhs_b = bls.g1_mul(&pk_point, &s_i);
+            let rhs_b = bls.g1_add(&b_i, &bls.g1_mul(&c_minus_mi_g, &e_i));
+            if lhs_b.to_bytes() != rhs_b.to_bytes() {
+                return false;
+            }
+
+            e_sum = e_sum + e_i;
+        }
+
+        e_sum.to_bytes() == e_total.to_bytes()
+    }
+
+    /// Hash a single attribute name/value pair into its scalar contribution
+    /// to the combined CL attribute message `m = Σ_i H(name_i || value_i)`.
+    fn attribute_scalar(env: &Env, name: &Symbol, value: &Bytes) -> Fr {
+        let mut pre = Bytes::from_slice(env, &name.to_val().get_payload().to_be_bytes());
+        pre.append(value);
+        Fr::from_bytes(env.crypto().sha256(&pre).into())
+    }
+
+    /// Register the CL issuer public key `(X, Y)` for a credential schema
+    /// (admin only).
+    pub fn register_cl_issuer(env: Env, schema: Symbol, x: BytesN<32>, y: BytesN<32>) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin)
+            .expect("Contract not initialized");
+        admin.require_auth();
+
+        let bls = env.crypto().bls12_381();
+        let gen2 = G2Affine::from_bytes(BytesN::from_array(&env, &G2_GENERATOR));
+        let x_g2 = bls.g2_mul(&gen2, &Fr::from_bytes(x)).to_bytes();
+        let y_g2 = bls.g2_mul(&gen2, &Fr::from_bytes(y)).to_bytes();
+
+        let key = ClIssuerKey { x_g2, y_g2 };
+        env.storage().persistent().set(&DataKey::CredentialIssuer(schema), &key);
+    }
+
+    /// Get the registered CL issuer public key for a schema, if any.
+    pub fn get_cl_issuer(env: Env, schema: Symbol) -> Option<ClIssuerKey> {
+        env.storage().persistent().get(&DataKey::CredentialIssuer(schema))
+    }
+
+    /// Issue a CL signature `(a, b, c)` over the holder's full attribute
+    /// bundle: `a = k*G1`, `b = y*a`, `c = (x + x*y*m)*a` where
+    /// `m = Σ_i H(name_i || value_i)` over every attribute in `attributes`.
+    ///
+    /// `x` and `y` must be the secret key registered for `schema` via
+    /// `register_cl_issuer`: their derived `(x*G2, y*G2)` is checked against
+    /// the stored `ClIssuerKey` before issuing, so a credential can only be
+    /// produced by whoever holds that schema's issuer secret.
+    pub fn issue_credential(
+        env: Env,
+        schema: Symbol,
+        x: BytesN<32>,
+        y: BytesN<32>,
+        attributes: Map<Symbol, Bytes>,
+        k: BytesN<32>,
+    ) -> ClCredential {
+        let issuer: ClIssuerKey = env.storage().persistent().get(&DataKey::CredentialIssuer(schema))
+            .expect("CL issuer not registered for schema");
+
+        let bls = env.crypto().bls12_381();
+        let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
+        let gen2 = G2Affine::from_bytes(BytesN::from_array(&env, &G2_GENERATOR));
+        let x_scalar = Fr::from_bytes(x);
+        let y_scalar = Fr::from_bytes(y);
+        let k_scalar = Fr::from_bytes(k);
+
+        let x_g2 = bls.g2_mul(&gen2, &x_scalar).to_bytes();
+        let y_g2 = bls.g2_mul(&gen2, &y_scalar).to_bytes();
+        if x_g2 != issuer.x_g2 || y_g2 != issuer.y_g2 {
+            panic!("x, y do not match the registered issuer key for schema");
+        }
+
+        let mut m = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32]));
+        for (name, value) in attributes.iter() {
+            m = m + Self::attribute_scalar(&env, &name, &value);
+        }
+
+        let a = bls.g1_mul(&gen_g, &k_scalar);
+        let b = bls.g1_mul(&a, &y_scalar);
+        let c_scalar = x_scalar.clone() + x_scalar * y_scalar * m;
+        let c = bls.g1_mul(&a, &c_scalar);
+
+        ClCredential { a: a.to_bytes(), b: b.to_bytes(), c: c.to_bytes() }
+    }
+
+    /// Rerandomize a CL credential by a fresh scalar `rho`: `a' = rho*a`,
+    /// `b' = rho*b`, `c' = rho*c`. The CL verification equation is
+    /// homogeneous under this scaling (bilinearity), so the randomized
+    /// triple verifies identically to the original, but is unlinkable to
+    /// it — two presentations of the same credential no longer share any
+    /// point in common. Call this before building a `ClPresentationProof`;
+    /// never present the credential returned by `issue_credential` directly.
+    pub fn randomize_credential(env: Env, credential: ClCredential, rho: BytesN<32>) -> ClCredential {
+        let bls = env.crypto().bls12_381();
+        let rho_scalar = Fr::from_bytes(rho);
+        let a = bls.g1_mul(&G1Affine::from_bytes(credential.a), &rho_scalar);
+        let b = bls.g1_mul(&G1Affine::from_bytes(credential.b), &rho_scalar);
+        let c = bls.g1_mul(&G1Affine::from_bytes(credential.c), &rho_scalar);
+        ClCredential { a: a.to_bytes(), b: b.to_bytes(), c: c.to_bytes() }
+    }
+
+    /// Verify a selective-disclosure presentation of a CL credential for
+    /// `schema`. The disclosed attributes fix `m_S`; the proof's
+    /// `hidden_commitment = m_H * b` stands in for the undisclosed
+    /// attributes' combined value `m_H` via bilinearity, so the pairing
+    /// check `e(X,a)·e(X,b)^{m_S}·e(X,b)^{m_H} == e(G2,c)` never requires
+    /// `m_H` itself. A Schnorr proof ties `hidden_commitment` to `b` so the
+    /// holder cannot substitute an arbitrary point there.
+    pub fn verify_presentation(
+        env: Env,
+        schema: Symbol,
+        disclosed: Map<Symbol, Bytes>,
+        proof: ClPresentationProof,
+    ) -> bool {
+        let issuer: ClIssuerKey = match env.storage().persistent().get(&DataKey::CredentialIssuer(schema)) {
+            Some(k) => k,
+            None => return false,
+        };
+
+        let bls = env.crypto().bls12_381();
+
+        let b = G1Affine::from_bytes(proof.credential.b.clone());
+        let hidden_commitment = G1Affine::from_bytes(proof.hidden_commitment.clone());
+        let dleq_t = G1Affine::from_bytes(proof.dleq_t.clone());
+        let dleq_z = Fr::from_bytes(proof.dleq_z);
+
+        // Schnorr proof of knowledge of m_H with hidden_commitment = m_H * b.
+        let mut challenge_pre = Bytes::new(&env);
+        challenge_pre.append(&proof.dleq_t.clone().into());
+        challenge_pre.append(&proof.credential.b.clone().into());
+        challenge_pre.append(&proof.hidden_commitment.clone().into());
+        let challenge = Fr::from_bytes(env.crypto().sha256(&challenge_pre).into());
+
+        let lhs = bls.g1_mul(&b, &dleq_z);
+        let rhs = bls.g1_add(&dleq_t, &bls.g1_mul(&hidden_commitment, &challenge));
+        if lhs.to_bytes() != rhs.to_bytes() {
+            return false;
+        }
+
+        // m_S from the disclosed attributes.
+        let mut m_s = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32]));
+        for (name, value) in disclosed.iter() {
+            m_s = m_s + Self::attribute_scalar(&env, &name, &value);
+        }
+
+        let one = Fr::from_bytes(BytesN::from_array(&env, &{
+            let mut bytes = [0u8; 32];
+            bytes[31] = 1;
+            bytes
+        }));
+        let neg_one = Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])) - one;
+        let c = G1Affine::from_bytes(proof.credential.c.clone());
+        let neg_c = bls.g1_mul(&c, &neg_one);
+
+        let mut g1_points: Vec<G1Affine> = Vec::new(&env);
+        g1_points.push_back(G1Affine::from_bytes(proof.credential.a));
+        g1_points.push_back(bls.g1_mul(&b, &m_s));
+        g1_points.push_back(hidden_commitment);
+        g1_points.push_back(neg_c);
+
+        let mut g2_points: Vec<G2Affine> = Vec::new(&env);
+        g2_points.push_back(G2Affine::from_bytes(issuer.x_g2.clone()));
+        g2_points.push_back(G2Affine::from_bytes(issuer.x_g2.clone()));
+        g2_points.push_back(G2Affine::from_bytes(issuer.x_g2));
+        g2_points.push_back(G2Affine::from_bytes(BytesN::from_array(&env, &G2_GENERATOR)));
+
+        bls.pairing_check(g1_points, g2_points)
+    }
+
     /// Create a set of keypairs for a ring
     pub fn create_keys(env: Env, ring_size: u32) -> KeyRingResult {
         let bls = env.crypto().bls12_381();
@@ -142,37 +693,73 @@ impl RingSigContract {
         }
     }
 
-    /// Sign a message using a ring signature
+    /// Sign a message using a linkable ring signature (LSAG). In addition to
+    /// the usual AOS/SAG challenge chain over `G`, a parallel chain is
+    /// carried over each member's `Hp(pk)` point so that the resulting
+    /// `image = sk * Hp(signer_pk)` can be checked for reuse at verification
+    /// time without revealing which ring member signed.
+    ///
+    /// The masking scalar and decoy responses are synthetic nonces in the
+    /// RedDSA style: they are derived by hashing the signer's secret key
+    /// together with the full ring, the message, and `extra` (fresh
+    /// caller-supplied entropy, e.g. a counter or random bytes), rather than
+    /// sampled from a fixed constant. Distinct (sk, ring, msg) triples — or
+    /// a distinct `extra` for the same triple — yield unpredictable,
+    /// unrelated nonces, so the secret key cannot be recovered from two
+    /// signatures the way it could when every signer reused the same
+    /// hardcoded masking scalar.
     pub fn sign(
         env: Env,
         msg: Bytes,
         ring: Vec<BytesN<96>>,
         secret_idx: u32,
         sk: BytesN<32>,
+        extra: Bytes,
     ) -> RingSignature {
         let bls = env.crypto().bls12_381();
         let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
+        let sk_bytes = sk.clone();
         let secret_key = Fr::from_bytes(sk);
         let mut updated_ring = ring.clone();
         let pk = bls.g1_mul(&gen_g, &secret_key).to_bytes();
-        updated_ring.set(secret_idx, pk);
+        updated_ring.set(secret_idx, pk.clone());
         let n = updated_ring.len() as usize;
         let secret_idx_usize = secret_idx as usize;
-        let random_a = env.crypto().sha256(&Bytes::from_slice(&env, &[42u8; 32]));
-        let a = Fr::from_bytes(random_a.into());
-        let mut responses: Vec<BytesN<32>> = Vec::new(&env);
-        for i in 0..n {
-            let random_r = env.crypto().sha256(&Bytes::from_slice(&env, &[i as u8 + 100; 32]));
-            responses.push_back(random_r.into());
-        }
+
+        let signer_hp = Self::hash_to_g1(&env, &pk);
+        let image = bls.g1_mul(&signer_hp, &secret_key);
+
         let mut base = Bytes::new(&env);
         for pk in updated_ring.iter() {
             base.append(&pk.into());
         }
         base.append(&msg);
+
+        let mut seed_pre = Bytes::from_slice(&env, b"RINGSIG-NONCE");
+        seed_pre.append(&sk_bytes.into());
+        seed_pre.append(&base);
+        seed_pre.append(&extra);
+        let seed = env.crypto().sha256(&seed_pre);
+
+        let mut a_pre = Bytes::new(&env);
+        a_pre.append(&seed.clone().into());
+        a_pre.append(&Bytes::from_slice(&env, &0u32.to_be_bytes()));
+        let a = Fr::from_bytes(env.crypto().sha256(&a_pre).into());
+
+        let mut responses: Vec<BytesN<32>> = Vec::new(&env);
+        for i in 0..n {
+            let mut r_pre = Bytes::new(&env);
+            r_pre.append(&seed.clone().into());
+            r_pre.append(&Bytes::from_slice(&env, &(i as u32).to_be_bytes()));
+            let random_r = env.crypto().sha256(&r_pre);
+            responses.push_back(random_r.into());
+        }
+
         let xs = bls.g1_mul(&gen_g, &a);
+        let ys = bls.g1_mul(&signer_hp, &a);
         let mut pre = base.clone();
         pre.append(&xs.to_bytes().into());
+        pre.append(&ys.to_bytes().into());
         let mut c: Vec<Fr> = Vec::new(&env);
         for _ in 0..n {
             c.push_back(Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])));
@@ -182,11 +769,17 @@ impl RingSigContract {
         while idx != secret_idx_usize {
             let r_i = Fr::from_bytes(responses.get_unchecked(idx as u32));
             let p_i = G1Affine::from_bytes(updated_ring.get_unchecked(idx as u32));
+            let hp_i = Self::hash_to_g1(&env, &updated_ring.get_unchecked(idx as u32));
+            let c_i = c.get_unchecked(idx as u32);
             let x1 = bls.g1_mul(&gen_g, &r_i);
-            let x2 = bls.g1_mul(&p_i, &c.get_unchecked(idx as u32));
+            let x2 = bls.g1_mul(&p_i, &c_i);
             let xi = bls.g1_add(&x1, &x2);
+            let y1 = bls.g1_mul(&hp_i, &r_i);
+            let y2 = bls.g1_mul(&image, &c_i);
+            let yi = bls.g1_add(&y1, &y2);
             let mut pre2 = base.clone();
             pre2.append(&xi.to_bytes().into());
+            pre2.append(&yi.to_bytes().into());
             let ci1 = Fr::from_bytes(env.crypto().sha256(&pre2).into());
             idx = (idx + 1) % n;
             c.set(idx as u32, ci1);
@@ -196,6 +789,7 @@ impl RingSigContract {
         RingSignature {
             challenge: c.get_unchecked(0).to_bytes(),
             responses,
+            image: image.to_bytes(),
         }
     }
 
@@ -206,29 +800,40 @@ impl RingSigContract {
         sig: RingSignature,
         attribute: Symbol
     ) -> bool {
-        let ring: Vec<BytesN<96>> = match env.storage().persistent().get(&DataKey::RingByAttribute(attribute)) {
+        let ring: Vec<BytesN<96>> = match env.storage().persistent().get(&DataKey::RingByAttribute(attribute.clone())) {
             Some(r) => r,
             None => return false,
         };
 
-        Self::verify_ring(env, msg, sig, ring)
+        Self::verify_ring(env, msg, sig, ring, attribute)
     }
 
-    /// Verify a ring signature against the default ring
+    /// Verify a ring signature against the default ring. Key images are
+    /// tracked under the `"default"` attribute bucket.
     pub fn verify(env: Env, msg: Bytes, sig: RingSignature) -> bool {
         let ring: Vec<BytesN<96>> = match env.storage().persistent().get(&DataKey::Ring) {
             Some(r) => r,
             None => return false,
         };
 
-        Self::verify_ring(env, msg, sig, ring)
+        Self::verify_ring(env, msg, sig, ring, symbol_short!("default"))
     }
 
-    /// Internal function to verify a ring signature
-    fn verify_ring(env: Env, msg: Bytes, sig: RingSignature, ring: Vec<BytesN<96>>) -> bool {
+    /// Internal function to verify a linkable ring signature. The challenge
+    /// chain is recomputed over both the `G` and `Hp` equations; if it
+    /// closes AND the signature's key image has not already been spent
+    /// against this attribute, the image is recorded so the same private
+    /// key cannot be spent again for this attribute.
+    fn verify_ring(env: Env, msg: Bytes, sig: RingSignature, ring: Vec<BytesN<96>>, attribute: Symbol) -> bool {
         if ring.is_empty() || ring.len() != sig.responses.len() {
             return false;
         }
+        let image = G1Affine::from_bytes(sig.image.clone());
+        let image_key = DataKey::KeyImages(attribute.clone(), sig.image.clone());
+        if env.storage().persistent().has(&image_key) {
+            return false;
+        }
+
         let bls = env.crypto().bls12_381();
         let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
         let mut base = Bytes::new(&env);
@@ -241,15 +846,21 @@ impl RingSigContract {
         for j in 0..n {
             let r_j = Fr::from_bytes(sig.responses.get_unchecked(j));
             let p_j = G1Affine::from_bytes(ring.get_unchecked(j));
+            let hp_j = Self::hash_to_g1(&env, &ring.get_unchecked(j));
             let x1 = bls.g1_mul(&gen_g, &r_j);
             let x2 = bls.g1_mul(&p_j, &c);
             let xj = bls.g1_add(&x1, &x2);
+            let y1 = bls.g1_mul(&hp_j, &r_j);
+            let y2 = bls.g1_mul(&image, &c);
+            let yj = bls.g1_add(&y1, &y2);
             let mut pre = base.clone();
             pre.append(&xj.to_bytes().into());
+            pre.append(&yj.to_bytes().into());
             c = Fr::from_bytes(env.crypto().sha256(&pre).into());
         }
         let ok = c == Fr::from_bytes(sig.challenge);
         if ok {
+            env.storage().persistent().set(&image_key, &true);
             env.storage()
                 .persistent()
                 .update(&DataKey::LoginCount, |opt: Option<u64>| -> u64 {
@@ -43,18 +43,183 @@ fn test_attribute_ring() {
     let client = RingSigContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    let issuer = Address::generate(&env);
     env.mock_all_auths();
 
     client.initialize(&admin);
 
+    // Single-signer (1-of-1) FROST group: Y = y*G.
+    let bls = env.crypto().bls12_381();
+    let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
+    let y_scalar = Fr::from_bytes(env.crypto().sha256(&Bytes::from_slice(&env, &[7u8; 32])).into());
+    let group_pk = bls.g1_mul(&gen_g, &y_scalar);
+
+    let mut participant_ids = Vec::new(&env);
+    participant_ids.push_back(1u32);
+    client.set_issuer_group(&group_pk.to_bytes(), &participant_ids);
+
     let attribute = symbol_short!("over_18");
     let mut users = Vec::new(&env);
     users.push_back(BytesN::from_array(&env, &[1u8; 96]));
     users.push_back(BytesN::from_array(&env, &[2u8; 96]));
 
-    client.create_ring_for_attribute(&issuer, &attribute, &users);
+    let mut signer_ids = Vec::new(&env);
+    signer_ids.push_back(1u32);
+
+    let nonce = Fr::from_bytes(env.crypto().sha256(&Bytes::from_slice(&env, &[8u8; 32])).into());
+    let r_point = bls.g1_mul(&gen_g, &nonce);
+
+    let mut msg = Bytes::from_slice(&env, &attribute.to_val().get_payload().to_be_bytes());
+    for u in users.iter() {
+        msg.append(&u.into());
+    }
+    for signer_id in signer_ids.iter() {
+        msg.append(&Bytes::from_slice(&env, &signer_id.to_be_bytes()));
+    }
+    let mut pre = Bytes::new(&env);
+    pre.append(&r_point.to_bytes().into());
+    pre.append(&group_pk.to_bytes().into());
+    pre.append(&msg);
+    let c = Fr::from_bytes(env.crypto().sha256(&pre).into());
+    let z = nonce + c * y_scalar;
+
+    let frost_sig = FrostSignature {
+        r: r_point.to_bytes(),
+        z: z.to_bytes(),
+    };
+
+    client.create_ring_for_attribute(&attribute, &users, &signer_ids, &frost_sig);
 
     let retrieved_ring = client.get_ring_for_attribute(&attribute);
     assert_eq!(retrieved_ring, Some(users));
 }
+
+#[test]
+fn test_encrypted_attribute_ring_proof() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, RingSigContract);
+    let client = RingSigContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let submitter = Address::generate(&env);
+    let bls = env.crypto().bls12_381();
+    let gen_g = G1Affine::from_bytes(BytesN::from_array(&env, &G1_GENERATOR));
+    let sk = Fr::from_bytes(env.crypto().sha256(&Bytes::from_slice(&env, &[9u8; 32])).into());
+    let pk = bls.g1_mul(&gen_g, &sk).to_bytes();
+
+    let attribute = symbol_short!("age_band");
+    let mut allowed_values = Vec::new(&env);
+    allowed_values.push_back(BytesN::from_array(&env, &[0u8; 32]));
+    allowed_values.push_back(BytesN::from_array(&env, &[1u8; 32]));
+    allowed_values.push_back(BytesN::from_array(&env, &[2u8; 32]));
+
+    let true_idx = 1u32;
+    let m = allowed_values.get_unchecked(true_idx);
+    let k = BytesN::from_array(&env, &[5u8; 32]);
+    let extra = Bytes::from_slice(&env, b"submission-nonce-1");
+
+    client.submit_encrypted_attribute(&submitter, &attribute, &pk, &allowed_values, &true_idx, &m, &k, &extra);
+
+    assert!(client.verify_encrypted_attribute(&submitter, &attribute));
+}
+
+#[test]
+fn test_cl_credential_selective_disclosure() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, RingSigContract);
+    let client = RingSigContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    let schema = symbol_short!("kyc_v1");
+    let x = BytesN::from_array(&env, &[11u8; 32]);
+    let y = BytesN::from_array(&env, &[12u8; 32]);
+    client.register_cl_issuer(&schema, &x, &y);
+
+    let name_attr = symbol_short!("name");
+    let country_attr = symbol_short!("country");
+    let name_val = Bytes::from_slice(&env, b"alice");
+    let country_val = Bytes::from_slice(&env, b"ie");
+
+    let mut attributes: Map<Symbol, Bytes> = Map::new(&env);
+    attributes.set(name_attr.clone(), name_val.clone());
+    attributes.set(country_attr.clone(), country_val.clone());
+
+    let k = BytesN::from_array(&env, &[13u8; 32]);
+    let credential = client.issue_credential(&schema, &x, &y, &attributes, &k);
+
+    let rho = BytesN::from_array(&env, &[15u8; 32]);
+    let credential = client.randomize_credential(&credential, &rho);
+
+    let bls = env.crypto().bls12_381();
+    let b_point = G1Affine::from_bytes(credential.b.clone());
+
+    let mut name_pre = Bytes::from_slice(&env, &name_attr.to_val().get_payload().to_be_bytes());
+    name_pre.append(&name_val);
+    let m_hidden = Fr::from_bytes(env.crypto().sha256(&name_pre).into());
+
+    let rho = Fr::from_bytes(env.crypto().sha256(&Bytes::from_slice(&env, &[14u8; 32])).into());
+    let hidden_commitment = bls.g1_mul(&b_point, &m_hidden);
+    let dleq_t = bls.g1_mul(&b_point, &rho);
+
+    let mut challenge_pre = Bytes::new(&env);
+    challenge_pre.append(&dleq_t.to_bytes().into());
+    challenge_pre.append(&credential.b.clone().into());
+    challenge_pre.append(&hidden_commitment.to_bytes().into());
+    let challenge = Fr::from_bytes(env.crypto().sha256(&challenge_pre).into());
+    let dleq_z = rho + challenge * m_hidden;
+
+    let proof = ClPresentationProof {
+        credential: credential.clone(),
+        hidden_commitment: hidden_commitment.to_bytes(),
+        dleq_t: dleq_t.to_bytes(),
+        dleq_z: dleq_z.to_bytes(),
+    };
+
+    let mut disclosed: Map<Symbol, Bytes> = Map::new(&env);
+    disclosed.set(country_attr, country_val);
+
+    assert!(client.verify_presentation(&schema, &disclosed, &proof));
+}
+
+#[test]
+fn test_sign_nonces_differ_by_secret_index() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, RingSigContract);
+    let client = RingSigContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let keys = client.create_keys(&3);
+    let msg = Bytes::from_slice(&env, b"login-challenge");
+    let extra = Bytes::from_slice(&env, b"same-entropy");
+
+    let sig_0 = client.sign(&msg, &keys.ring, &0u32, &keys.secret_keys.get_unchecked(0), &extra);
+    let sig_1 = client.sign(&msg, &keys.ring, &1u32, &keys.secret_keys.get_unchecked(1), &extra);
+
+    assert_ne!(sig_0.challenge, sig_1.challenge);
+    assert_ne!(sig_0.responses, sig_1.responses);
+}
+
+#[test]
+fn test_verify_round_trip_and_replay_rejection() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, RingSigContract);
+    let client = RingSigContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+
+    let keys = client.create_keys(&3);
+    client.init(&keys.ring);
+
+    let msg = Bytes::from_slice(&env, b"login-challenge");
+    let extra = Bytes::from_slice(&env, b"nonce-1");
+    let sig = client.sign(&msg, &keys.ring, &1u32, &keys.secret_keys.get_unchecked(1), &extra);
+
+    assert_eq!(client.get_login_count(), 0);
+    assert!(client.verify(&msg, &sig));
+    assert_eq!(client.get_login_count(), 1);
+
+    // Replaying the same signature (same key image) must be rejected.
+    assert!(!client.verify(&msg, &sig));
+    assert_eq!(client.get_login_count(), 1);
+}